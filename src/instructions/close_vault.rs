@@ -0,0 +1,151 @@
+// CloseVault instruction: reclaims the vault's residual lamports back to the withdraw
+// authority once the vesting schedule has been fully paid out, and closes the
+// vault_state account alongside it. Leaving both accounts at 0 lamports with
+// vault_state's data zeroed means the runtime garbage-collects them at the end
+// of the transaction.
+
+use pinocchio::{account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{find_program_address, Pubkey}, ProgramResult};
+use pinocchio_system::instructions::Transfer;
+use core::mem::size_of;
+
+use crate::state::VaultState;
+
+// Account struct for the close-vault instruction
+pub struct CloseVaultAccounts<'a> {
+    pub authority: &'a AccountInfo,   // Withdraw authority closing the vault (must be signer, receives the rent)
+    pub vault: &'a AccountInfo,       // PDA vault account being closed
+    pub vault_state: &'a AccountInfo, // PDA vesting state account for the creator
+    pub bumps: [u8; 1],               // Bump seed for vault PDA signing
+}
+
+impl<'a> TryFrom<(&'a [AccountInfo], &Pubkey)> for CloseVaultAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from((accounts, creator): (&'a [AccountInfo], &Pubkey)) -> Result<Self, Self::Error> {
+        let [authority, vault, vault_state, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !authority.is_signer() {
+            return Err(ProgramError::InvalidAccountOwner);  // Authority must sign the transaction
+        }
+
+        if unsafe { vault.owner().ne(&pinocchio_system::ID) } {
+            return Err(ProgramError::InvalidAccountOwner);  // Vault must be owned by System Program
+        }
+
+        // Verify vault is the correct PDA for the creator and get bump seed
+        let (vault_key, bump) = find_program_address(&[b"vault", creator.as_ref()], &crate::ID);
+        if vault.key().ne(&vault_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Verify vault_state is the correct PDA for the creator and is owned by this program
+        let (vault_state_key, _) = find_program_address(&[VaultState::SEED, creator.as_ref()], &crate::ID);
+        if vault_state.key().ne(&vault_state_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if unsafe { vault_state.owner() } != &crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);  // Vault state must be owned by this program
+        }
+
+        // Only the withdraw authority may close the vault, and only once the vesting
+        // schedule has been fully paid out - closing early would hand the still-unvested
+        // balance to the authority ahead of the vesting schedule.
+        let state = VaultState::load(&vault_state.try_borrow_data()?)?;
+        state.check_withdraw_authority(authority.key())?;
+        if !state.is_fully_paid_out() {
+            return Err(ProgramError::InvalidAccountData);  // Vesting schedule isn't fully paid out yet
+        }
+
+        Ok(Self { authority, vault, vault_state, bumps: [bump] })
+    }
+}
+
+// Instruction data struct - the creator whose deposit the vault PDAs are seeded from
+pub struct CloseVaultInstructionData {
+    pub creator: Pubkey,  // Original depositor, used to re-derive the vault PDAs
+}
+
+impl<'a> TryFrom<&'a [u8]> for CloseVaultInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<Pubkey>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let creator: Pubkey = data.try_into().unwrap();
+
+        Ok(Self { creator })
+    }
+}
+
+// Main close-vault instruction struct - combines accounts and instruction data
+pub struct CloseVault<'a> {
+    pub accounts: CloseVaultAccounts<'a>,
+    pub instruction_datas: CloseVaultInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for CloseVault<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        // Parse the instruction data first - the creator it carries is needed to
+        // validate the accounts (which PDAs to expect)
+        let instruction_datas = CloseVaultInstructionData::try_from(data)?;
+        let accounts = CloseVaultAccounts::try_from((accounts, &instruction_datas.creator))?;
+
+        Ok(Self { accounts, instruction_datas })
+    }
+}
+
+impl<'a> CloseVault<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &6;  // Single-byte discriminator for close-vault instruction
+
+    pub fn process(&mut self) -> ProgramResult {
+        let seeds = [
+            Seed::from(b"vault"),
+            Seed::from(self.instruction_datas.creator.as_ref()),
+            Seed::from(&self.accounts.bumps),
+        ];
+        let signers = [Signer::from(&seeds)];
+
+        Transfer {
+            from: self.accounts.vault,
+            to: self.accounts.authority,
+            lamports: self.accounts.vault.lamports(),
+        }
+        .invoke_signed(&signers)?;
+
+        // vault_state is owned by this program, not the System Program, so its
+        // lamports can't move through a system Transfer CPI - reclaim them (and
+        // the account itself) by draining the lamports field directly and
+        // zeroing the data, which is sufficient for the runtime to treat it as
+        // closed at the end of the transaction.
+        let vault_state_lamports = self.accounts.vault_state.lamports();
+        *self.accounts.vault_state.try_borrow_mut_lamports()? = 0;
+        *self.accounts.authority.try_borrow_mut_lamports()? += vault_state_lamports;
+        self.accounts.vault_state.try_borrow_mut_data()?.fill(0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instruction_data_rejects_wrong_length() {
+        assert!(CloseVaultInstructionData::try_from(&[0u8; 31][..]).is_err());
+        assert!(CloseVaultInstructionData::try_from(&[0u8; 33][..]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_parses_creator() {
+        let parsed = CloseVaultInstructionData::try_from(&[5u8; 32][..]).unwrap();
+        assert_eq!(parsed.creator, [5; 32]);
+    }
+}
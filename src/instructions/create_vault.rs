@@ -0,0 +1,85 @@
+// CreateVault instruction: performs the canonical allocate/assign/fund sequence for
+// the vault PDA (allocate and assign signed by the `[b"vault", owner, bump]` seeds,
+// the funding transfer signed by the owner), so the vault has an
+// explicit creation step instead of relying on the implicit precondition that a
+// bare system-owned PDA with a balance already exists.
+
+use pinocchio::{account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::find_program_address, sysvars::{rent::Rent, Sysvar}, ProgramResult};
+use pinocchio_system::instructions::{Allocate, Assign, Transfer};
+
+// Account struct for the create-vault instruction
+pub struct CreateVaultAccounts<'a> {
+    pub owner: &'a AccountInfo,  // User creating the vault (must be signer, pays the rent)
+    pub vault: &'a AccountInfo,  // PDA vault account being created
+    pub bumps: [u8; 1],          // Bump seed for PDA signing
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CreateVaultAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [owner, vault, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !owner.is_signer() {
+            return Err(ProgramError::InvalidAccountOwner);  // Owner must sign the transaction
+        }
+
+        // Verify vault is the correct PDA for this owner and get bump seed
+        let (vault_key, bump) = find_program_address(&[b"vault", owner.key()], &crate::ID);
+        if vault.key().ne(&vault_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if vault.lamports().ne(&0) {
+            return Err(ProgramError::InvalidAccountData);  // Vault must not already exist
+        }
+
+        Ok(Self { owner, vault, bumps: [bump] })
+    }
+}
+
+// Main create-vault instruction struct - contains validated accounts
+pub struct CreateVault<'a> {
+    pub accounts: CreateVaultAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CreateVault<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = CreateVaultAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> CreateVault<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &5;  // Single-byte discriminator for create-vault instruction
+
+    pub fn process(&mut self) -> ProgramResult {
+        let seeds = [
+            Seed::from(b"vault"),
+            Seed::from(self.accounts.owner.key().as_ref()),
+            Seed::from(&self.accounts.bumps),
+        ];
+        let signers = [Signer::from(&seeds)];
+
+        // Pure-lamport vault: no space is needed, so allocate/assign are effectively
+        // no-ops, but they make vault creation an explicit, signed step.
+        Allocate { account: self.accounts.vault, space: 0 }.invoke_signed(&signers)?;
+        Assign { account: self.accounts.vault, owner: &pinocchio_system::ID }.invoke_signed(&signers)?;
+
+        // Fund the vault to the rent-exempt minimum so it survives on the ledger
+        let rent = Rent::get()?;
+        Transfer {
+            from: self.accounts.owner,
+            to: self.accounts.vault,
+            lamports: rent.minimum_balance(0),
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+}
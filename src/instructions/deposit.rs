@@ -1,72 +1,100 @@
 // Deposit instruction: handles user deposits into the vault
 // Validates accounts and instruction data, then performs the lamport transfer
+// and initializes the vault's vesting state on first deposit.
 
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::find_program_address, ProgramResult};
-use pinocchio_system::instructions::Transfer;
+use pinocchio::{account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{find_program_address, Pubkey}, sysvars::{rent::Rent, Sysvar}, ProgramResult};
+use pinocchio_system::instructions::{CreateAccount, Transfer};
 use core::mem::size_of;
 
-// Account struct for deposit instruction - contains owner and vault accounts
+use crate::state::VaultState;
+
+// Account struct for deposit instruction - contains owner, vault, and vesting state accounts
 pub struct DepositAccounts<'a> {
-    pub owner: &'a AccountInfo,  // User making the deposit (must be signer)
-    pub vault: &'a AccountInfo,  // PDA vault account to receive lamports
+    pub owner: &'a AccountInfo,       // User making the deposit (must be signer)
+    pub vault: &'a AccountInfo,       // PDA vault account to receive lamports
+    pub vault_state: &'a AccountInfo, // PDA vesting state account, created on first deposit
 }
- 
+
 impl<'a> TryFrom<&'a [AccountInfo]> for DepositAccounts<'a> {
     type Error = ProgramError;
- 
+
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        // Destructure accounts slice - expect owner, vault, and system program
-        let [owner, vault, _] = accounts else {
+        // Destructure accounts slice - expect owner, vault, vault_state, and system program
+        let [owner, vault, vault_state, _] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
- 
+
         // Account validation checks
         if !owner.is_signer() {
             return Err(ProgramError::InvalidAccountOwner);  // Owner must sign the transaction
         }
- 
+
         if unsafe { vault.owner().ne(&pinocchio_system::ID) } {
             return Err(ProgramError::InvalidAccountOwner);  // Vault must be owned by System Program
         }
- 
-        if vault.lamports().ne(&0) {
-            return Err(ProgramError::InvalidAccountData);  // Vault must be empty (no double deposits)
+
+        // A vault is "empty" either because it's a raw PDA (0 lamports) or because
+        // `CreateVault` has funded it to the rent-exempt floor but no deposit has
+        // landed yet. Anything above that floor means a deposit already happened.
+        if vault.lamports().gt(&Rent::get()?.minimum_balance(0)) {
+            return Err(ProgramError::InvalidAccountData);  // Vault already holds a deposit
         }
- 
+
         // Verify vault is the correct PDA for this owner
         let (vault_key, _) = find_program_address(&[b"vault", owner.key()], &crate::ID);
         if vault.key().ne(&vault_key) {
             return Err(ProgramError::InvalidAccountOwner);
         }
- 
+
+        // Verify vault_state is the correct PDA for this owner and that it hasn't been created yet
+        let (vault_state_key, _) = find_program_address(&[VaultState::SEED, owner.key()], &crate::ID);
+        if vault_state.key().ne(&vault_state_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if vault_state.lamports().ne(&0) {
+            return Err(ProgramError::InvalidAccountData);  // Vesting state must not already exist
+        }
+
         // Return validated accounts
-        Ok(Self { owner, vault })
+        Ok(Self { owner, vault, vault_state })
     }
 }
 
-// Instruction data struct - contains the deposit amount
+// Instruction data struct - contains the deposit amount, the vesting window, and
+// the withdraw authority that will be allowed to claim the vested balance
 pub struct DepositInstructionData {
-    pub amount: u64,  // Amount of lamports to deposit
+    pub amount: u64,               // Amount of lamports to deposit
+    pub start_ts: i64,             // Vesting start timestamp (unix seconds)
+    pub end_ts: i64,               // Vesting end timestamp (unix seconds)
+    pub withdraw_authority: Pubkey, // Only signer allowed to withdraw from this vault
 }
- 
+
 impl<'a> TryFrom<&'a [u8]> for DepositInstructionData {
     type Error = ProgramError;
- 
+
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        // Verify data length matches u64 size (8 bytes)
-        if data.len() != size_of::<u64>() {
+        // Verify data length matches amount (u64) + start_ts (i64) + end_ts (i64) + withdraw_authority (Pubkey)
+        if data.len() != size_of::<u64>() + size_of::<i64>() * 2 + size_of::<Pubkey>() {
             return Err(ProgramError::InvalidInstructionData);
         }
- 
-        // Convert byte slice to u64 (little-endian)
-        let amount = u64::from_le_bytes(data.try_into().unwrap());
- 
+
+        // Convert byte slices to their respective types (little-endian)
+        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+        let start_ts = i64::from_le_bytes(data[8..16].try_into().unwrap());
+        let end_ts = i64::from_le_bytes(data[16..24].try_into().unwrap());
+        let withdraw_authority: Pubkey = data[24..56].try_into().unwrap();
+
         // Instruction validation
         if amount.eq(&0) {
             return Err(ProgramError::InvalidInstructionData);  // Amount must be greater than 0
         }
- 
-        Ok(Self { amount })
+
+        if end_ts.le(&start_ts) {
+            return Err(ProgramError::InvalidInstructionData);  // Vesting window must be non-empty
+        }
+
+        Ok(Self { amount, start_ts, end_ts, withdraw_authority })
     }
 }
 
@@ -75,25 +103,25 @@ pub struct Deposit<'a> {
     pub accounts: DepositAccounts<'a>,
     pub instruction_datas: DepositInstructionData,
 }
- 
+
 impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Deposit<'a> {
     type Error = ProgramError;
- 
+
     fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
         // Validate accounts and instruction data
         let accounts = DepositAccounts::try_from(accounts)?;
         let instruction_datas: DepositInstructionData = DepositInstructionData::try_from(data)?;
- 
+
         Ok(Self {
             accounts,
             instruction_datas,
         })
     }
 }
- 
+
 impl<'a> Deposit<'a> {
     pub const DISCRIMINATOR: &'a u8 = &0;  // Single-byte discriminator for deposit instruction
- 
+
     pub fn process(&mut self) -> ProgramResult {
         // Perform the lamport transfer from owner to vault using CPI
         Transfer {
@@ -102,10 +130,78 @@ impl<'a> Deposit<'a> {
             lamports: self.instruction_datas.amount,  // Amount to transfer
         }
         .invoke()?;  // Execute the transfer
- 
+
+        // Create the vesting state account, signed by its own PDA seeds
+        let (_, bump) = find_program_address(
+            &[VaultState::SEED, self.accounts.owner.key()],
+            &crate::ID,
+        );
+        let bump = [bump];
+        let seeds = [
+            Seed::from(VaultState::SEED),
+            Seed::from(self.accounts.owner.key().as_ref()),
+            Seed::from(&bump),
+        ];
+        let signers = [Signer::from(&seeds)];
+
+        CreateAccount {
+            from: self.accounts.owner,
+            to: self.accounts.vault_state,
+            lamports: Rent::get()?.minimum_balance(VaultState::LEN),
+            space: VaultState::LEN as u64,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signers)?;
+
+        // Record the vesting schedule
+        let mut data = self.accounts.vault_state.try_borrow_mut_data()?;
+        let state = VaultState::load_mut(&mut data)?;
+        state.start_ts = self.instruction_datas.start_ts;
+        state.end_ts = self.instruction_datas.end_ts;
+        state.total_deposited = self.instruction_datas.amount;
+        state.already_withdrawn = 0;
+        state.withdraw_authority = self.instruction_datas.withdraw_authority;
+
         Ok(())
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    fn data(amount: u64, start_ts: i64, end_ts: i64, withdraw_authority: Pubkey) -> [u8; 56] {
+        let mut buf = [0u8; 56];
+        buf[0..8].copy_from_slice(&amount.to_le_bytes());
+        buf[8..16].copy_from_slice(&start_ts.to_le_bytes());
+        buf[16..24].copy_from_slice(&end_ts.to_le_bytes());
+        buf[24..56].copy_from_slice(&withdraw_authority);
+        buf
+    }
+
+    #[test]
+    fn instruction_data_rejects_wrong_length() {
+        assert!(DepositInstructionData::try_from(&data(1, 0, 1, [1; 32])[..55]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_rejects_zero_amount() {
+        assert!(DepositInstructionData::try_from(&data(0, 0, 1, [1; 32])[..]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_rejects_non_positive_vesting_window() {
+        assert!(DepositInstructionData::try_from(&data(1, 100, 100, [1; 32])[..]).is_err());
+        assert!(DepositInstructionData::try_from(&data(1, 100, 50, [1; 32])[..]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_parses_fields() {
+        let parsed = DepositInstructionData::try_from(&data(10, 100, 200, [4; 32])[..]).unwrap();
+        assert_eq!(parsed.amount, 10);
+        assert_eq!(parsed.start_ts, 100);
+        assert_eq!(parsed.end_ts, 200);
+        assert_eq!(parsed.withdraw_authority, [4; 32]);
+    }
+}
 
@@ -0,0 +1,18 @@
+// Instruction module: re-exports each instruction handler so callers can
+// write `Deposit`, `Withdraw`, etc. without the `instructions::` prefix.
+
+pub mod deposit;
+pub mod withdraw;
+pub mod token_deposit;
+pub mod token_withdraw;
+pub mod relay;
+pub mod create_vault;
+pub mod close_vault;
+
+pub use deposit::*;
+pub use withdraw::*;
+pub use token_deposit::*;
+pub use token_withdraw::*;
+pub use relay::*;
+pub use create_vault::*;
+pub use close_vault::*;
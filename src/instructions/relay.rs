@@ -0,0 +1,212 @@
+// Relay instruction: lets the vault PDA sign an arbitrary CPI into a program on a
+// fixed allow-list, so vault-custodied lamports can flow into integrations
+// (staking, DEX deposits, ...) while the vault retains signing authority.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+use core::mem::size_of;
+
+use crate::state::VaultState;
+
+// Programs the vault is allowed to relay a signed CPI into. This intentionally
+// ships empty: no integration (staking, DEX, ...) has been wired up yet, so
+// there's no real program id to trust. Populate it with the integrator's
+// program id(s) before `Relay` is usable in production - until then every
+// `Relay` call correctly fails closed in `is_whitelisted` below rather than
+// signing a CPI into an untrusted program.
+pub const WHITELIST: &[Pubkey] = &[];
+
+// Pulled out of `process` so the allow-list check can be unit-tested against a
+// local non-empty list without needing a populated `WHITELIST` or real
+// `AccountInfo` fixtures.
+fn is_whitelisted(target: &Pubkey, whitelist: &[Pubkey]) -> bool {
+    whitelist.contains(target)
+}
+
+// Remaining accounts are passed through to the relayed instruction as-is; bound
+// the count so we can build the `AccountMeta`/`AccountInfo` arrays on the stack.
+pub const MAX_RELAY_ACCOUNTS: usize = 8;
+
+// Account struct for the relay instruction
+pub struct RelayAccounts<'a> {
+    pub authority: &'a AccountInfo,     // Withdraw authority directing the relay (must be signer)
+    pub vault: &'a AccountInfo,         // Vault PDA, signs the relayed CPI
+    pub vault_state: &'a AccountInfo,   // PDA vesting state account for the creator
+    pub bumps: [u8; 1],                 // Bump seed for vault PDA signing
+    pub remaining: &'a [AccountInfo],   // Accounts forwarded to the relayed instruction
+}
+
+impl<'a> TryFrom<(&'a [AccountInfo], &Pubkey)> for RelayAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from((accounts, creator): (&'a [AccountInfo], &Pubkey)) -> Result<Self, Self::Error> {
+        let [authority, vault, vault_state, remaining @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !authority.is_signer() {
+            return Err(ProgramError::InvalidAccountOwner);  // Authority must sign the transaction
+        }
+
+        // Verify vault is the correct PDA for the creator and get bump seed
+        let (vault_key, bump) = find_program_address(&[b"vault", creator.as_ref()], &crate::ID);
+        if vault.key().ne(&vault_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Verify vault_state is the correct PDA for the creator and is owned by this program
+        let (vault_state_key, _) = find_program_address(&[VaultState::SEED, creator.as_ref()], &crate::ID);
+        if vault_state.key().ne(&vault_state_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if unsafe { vault_state.owner() } != &crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);  // Vault state must be owned by this program
+        }
+
+        // Only the withdraw authority may direct the vault's signing power into a CPI,
+        // same as a `Withdraw` - otherwise the relay would let the creator bypass both
+        // the vesting schedule and the withdraw-authority separation.
+        let state = VaultState::load(&vault_state.try_borrow_data()?)?;
+        state.check_withdraw_authority(authority.key())?;
+
+        if remaining.len() > MAX_RELAY_ACCOUNTS {
+            return Err(ProgramError::InvalidInstructionData);  // Too many relayed accounts
+        }
+
+        Ok(Self { authority, vault, vault_state, bumps: [bump], remaining })
+    }
+}
+
+// Instruction data struct - the creator the vault PDAs are seeded from, the target
+// program id, and the serialized inner instruction
+pub struct RelayInstructionData<'a> {
+    pub creator: Pubkey,
+    pub target_program: Pubkey,
+    pub inner_data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for RelayInstructionData<'a> {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < size_of::<Pubkey>() * 2 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (creator, rest) = data.split_at(size_of::<Pubkey>());
+        let (target_program, inner_data) = rest.split_at(size_of::<Pubkey>());
+        let creator: Pubkey = creator.try_into().unwrap();
+        let target_program: Pubkey = target_program.try_into().unwrap();
+
+        Ok(Self { creator, target_program, inner_data })
+    }
+}
+
+// Main relay instruction struct - combines accounts and instruction data
+pub struct Relay<'a> {
+    pub accounts: RelayAccounts<'a>,
+    pub instruction_datas: RelayInstructionData<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Relay<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        // Parse the instruction data first - the creator it carries is needed to
+        // validate the accounts (which PDAs to expect)
+        let instruction_datas = RelayInstructionData::try_from(data)?;
+        let accounts = RelayAccounts::try_from((accounts, &instruction_datas.creator))?;
+
+        Ok(Self { accounts, instruction_datas })
+    }
+}
+
+impl<'a> Relay<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &4;  // Single-byte discriminator for relay instruction
+
+    pub fn process(&mut self) -> ProgramResult {
+        if !is_whitelisted(&self.instruction_datas.target_program, WHITELIST) {
+            return Err(ProgramError::InvalidAccountOwner);  // Target program isn't allow-listed
+        }
+
+        let remaining = self.accounts.remaining;
+        let vault = self.accounts.vault;
+
+        // Build the AccountMeta/AccountInfo arrays on the stack, padded with the
+        // vault account past `remaining.len()` (the padding is never read by the CPI).
+        let metas: [AccountMeta; MAX_RELAY_ACCOUNTS] = core::array::from_fn(|i| {
+            if let Some(info) = remaining.get(i) {
+                AccountMeta {
+                    pubkey: info.key(),
+                    is_writable: info.is_writable(),
+                    is_signer: info.is_signer(),
+                }
+            } else {
+                AccountMeta { pubkey: vault.key(), is_writable: false, is_signer: false }
+            }
+        });
+        let infos: [&AccountInfo; MAX_RELAY_ACCOUNTS] =
+            core::array::from_fn(|i| remaining.get(i).unwrap_or(vault));
+
+        let instruction = Instruction {
+            program_id: &self.instruction_datas.target_program,
+            accounts: &metas[..remaining.len()],
+            data: self.instruction_datas.inner_data,
+        };
+
+        // Create signer seeds for PDA signing - allows the vault to sign the relayed CPI
+        let seeds = [
+            Seed::from(b"vault"),
+            Seed::from(self.instruction_datas.creator.as_ref()),
+            Seed::from(&self.accounts.bumps),
+        ];
+        let signers = [Signer::from(&seeds)];
+
+        invoke_signed(&instruction, &infos[..remaining.len()], &signers)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_whitelisted_rejects_against_empty_list() {
+        assert!(!is_whitelisted(&[1; 32], WHITELIST));
+    }
+
+    #[test]
+    fn is_whitelisted_accepts_listed_program() {
+        let local_whitelist = [[1; 32], [2; 32]];
+        assert!(is_whitelisted(&[2; 32], &local_whitelist));
+        assert!(!is_whitelisted(&[3; 32], &local_whitelist));
+    }
+
+    #[test]
+    fn instruction_data_rejects_short_buffers() {
+        let data = [0u8; 63]; // one byte short of two pubkeys
+        assert!(RelayInstructionData::try_from(&data[..]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_parses_creator_target_and_inner_data() {
+        let mut data = [0u8; 70];
+        data[0..32].copy_from_slice(&[1; 32]);
+        data[32..64].copy_from_slice(&[2; 32]);
+        data[64..70].copy_from_slice(b"inner!");
+
+        let parsed = RelayInstructionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.creator, [1; 32]);
+        assert_eq!(parsed.target_program, [2; 32]);
+        assert_eq!(parsed.inner_data, b"inner!");
+    }
+}
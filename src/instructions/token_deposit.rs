@@ -0,0 +1,137 @@
+// TokenDeposit instruction: handles SPL-token deposits into the vault's associated
+// token account. Mirrors `Deposit`, but moves tokens instead of lamports.
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::find_program_address, ProgramResult};
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+use core::mem::size_of;
+
+// Account struct for the token deposit instruction
+pub struct TokenDepositAccounts<'a> {
+    pub owner: &'a AccountInfo,              // User making the deposit (must be signer)
+    pub vault: &'a AccountInfo,               // Vault PDA, the vault token account's authority
+    pub owner_token_account: &'a AccountInfo, // Owner's SPL token account, debited
+    pub vault_token_account: &'a AccountInfo, // Vault's SPL token account, credited
+    pub mint: &'a AccountInfo,               // Mint of the token being deposited
+    pub token_program: &'a AccountInfo,      // SPL token program
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for TokenDepositAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [owner, vault, owner_token_account, vault_token_account, mint, token_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !owner.is_signer() {
+            return Err(ProgramError::InvalidAccountOwner);  // Owner must sign the transaction
+        }
+
+        if token_program.key().ne(&pinocchio_token::ID) {
+            return Err(ProgramError::InvalidAccountOwner);  // Must be the real SPL token program
+        }
+
+        // The vault account must be the canonical vault PDA for this owner
+        let (vault_key, _) = find_program_address(&[b"vault", owner.key()], &crate::ID);
+        if vault.key().ne(&vault_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // The vault token account must be owned (as its SPL token authority) by the vault PDA
+        let vault_token = TokenAccount::from_account_info(vault_token_account)?;
+        if vault_token.owner().ne(&vault_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_token.mint().ne(mint.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            owner,
+            vault,
+            owner_token_account,
+            vault_token_account,
+            mint,
+            token_program,
+        })
+    }
+}
+
+// Instruction data struct - contains the deposit amount
+pub struct TokenDepositInstructionData {
+    pub amount: u64,  // Amount of tokens to deposit
+}
+
+impl<'a> TryFrom<&'a [u8]> for TokenDepositInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u64>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let amount = u64::from_le_bytes(data.try_into().unwrap());
+
+        if amount.eq(&0) {
+            return Err(ProgramError::InvalidInstructionData);  // Amount must be greater than 0
+        }
+
+        Ok(Self { amount })
+    }
+}
+
+// Main token deposit instruction struct - combines accounts and instruction data
+pub struct TokenDeposit<'a> {
+    pub accounts: TokenDepositAccounts<'a>,
+    pub instruction_datas: TokenDepositInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for TokenDeposit<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = TokenDepositAccounts::try_from(accounts)?;
+        let instruction_datas = TokenDepositInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, instruction_datas })
+    }
+}
+
+impl<'a> TokenDeposit<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &2;  // Single-byte discriminator for token deposit instruction
+
+    pub fn process(&mut self) -> ProgramResult {
+        // Owner signs for their own token account - no PDA signature needed here
+        Transfer {
+            from: self.accounts.owner_token_account,
+            to: self.accounts.vault_token_account,
+            authority: self.accounts.owner,
+            amount: self.instruction_datas.amount,
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instruction_data_rejects_wrong_length() {
+        assert!(TokenDepositInstructionData::try_from(&[0u8; 7][..]).is_err());
+        assert!(TokenDepositInstructionData::try_from(&[0u8; 9][..]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_rejects_zero_amount() {
+        assert!(TokenDepositInstructionData::try_from(&0u64.to_le_bytes()[..]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_parses_amount() {
+        let parsed = TokenDepositInstructionData::try_from(&42u64.to_le_bytes()[..]).unwrap();
+        assert_eq!(parsed.amount, 42);
+    }
+}
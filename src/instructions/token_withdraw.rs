@@ -0,0 +1,183 @@
+// TokenWithdraw instruction: handles SPL-token withdrawals from the vault's
+// associated token account, signed by the `[b"vault", creator, bump]` PDA.
+// The PDA is still seeded from the original depositor (the "creator"), but
+// release is gated on the stored `withdraw_authority`, same as the lamport
+// `Withdraw` instruction. `VaultState`'s vesting schedule tracks only the
+// lamport deposit made through `Deposit`/`Withdraw` and has no per-mint
+// bookkeeping, so it does not gate the token path - once authorized, the
+// withdraw authority may claim the vault's tokens in full at any time.
+
+use pinocchio::{account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{find_program_address, Pubkey}, ProgramResult};
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+use core::mem::size_of;
+
+use crate::state::VaultState;
+
+// Account struct for the token withdraw instruction
+pub struct TokenWithdrawAccounts<'a> {
+    pub authority: &'a AccountInfo,           // Withdraw authority claiming the tokens (must be signer)
+    pub vault: &'a AccountInfo,               // Vault PDA, the vault token account's authority
+    pub vault_state: &'a AccountInfo,         // PDA vesting state account for the creator
+    pub owner_token_account: &'a AccountInfo, // Authority's SPL token account, credited
+    pub vault_token_account: &'a AccountInfo, // Vault's SPL token account, debited
+    pub mint: &'a AccountInfo,               // Mint of the token being withdrawn
+    pub token_program: &'a AccountInfo,      // SPL token program
+    pub bumps: [u8; 1],                      // Bump seed for vault PDA signing
+}
+
+impl<'a> TryFrom<(&'a [AccountInfo], &Pubkey)> for TokenWithdrawAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from((accounts, creator): (&'a [AccountInfo], &Pubkey)) -> Result<Self, Self::Error> {
+        let [authority, vault, vault_state, owner_token_account, vault_token_account, mint, token_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !authority.is_signer() {
+            return Err(ProgramError::InvalidAccountOwner);  // Authority must sign the transaction
+        }
+
+        if token_program.key().ne(&pinocchio_token::ID) {
+            return Err(ProgramError::InvalidAccountOwner);  // Must be the real SPL token program
+        }
+
+        // The vault account must be the canonical vault PDA for this creator
+        let (vault_key, bump) = find_program_address(&[b"vault", creator.as_ref()], &crate::ID);
+        if vault.key().ne(&vault_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Verify vault_state is the correct PDA for the creator and is owned by this program
+        let (vault_state_key, _) = find_program_address(&[VaultState::SEED, creator.as_ref()], &crate::ID);
+        if vault_state.key().ne(&vault_state_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if unsafe { vault_state.owner() } != &crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);  // Vault state must be owned by this program
+        }
+
+        // Only the withdraw authority recorded at deposit time may release tokens,
+        // same custody model as the lamport `Withdraw`.
+        let state = VaultState::load(&vault_state.try_borrow_data()?)?;
+        state.check_withdraw_authority(authority.key())?;
+
+        // The vault token account's authority must be this creator's vault PDA
+        let vault_token = TokenAccount::from_account_info(vault_token_account)?;
+        if vault_token.owner().ne(&vault_key) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_token.mint().ne(mint.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            authority,
+            vault,
+            vault_state,
+            owner_token_account,
+            vault_token_account,
+            mint,
+            token_program,
+            bumps: [bump],
+        })
+    }
+}
+
+// Instruction data struct - the withdrawal amount and the creator whose deposit
+// the vault/vault_state PDAs are seeded from
+pub struct TokenWithdrawInstructionData {
+    pub amount: u64,      // Amount of tokens to withdraw
+    pub creator: Pubkey,  // Original depositor, used to re-derive the vault PDAs
+}
+
+impl<'a> TryFrom<&'a [u8]> for TokenWithdrawInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u64>() + size_of::<Pubkey>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+        let creator: Pubkey = data[8..40].try_into().unwrap();
+
+        if amount.eq(&0) {
+            return Err(ProgramError::InvalidInstructionData);  // Amount must be greater than 0
+        }
+
+        Ok(Self { amount, creator })
+    }
+}
+
+// Main token withdraw instruction struct - combines accounts and instruction data
+pub struct TokenWithdraw<'a> {
+    pub accounts: TokenWithdrawAccounts<'a>,
+    pub instruction_datas: TokenWithdrawInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for TokenWithdraw<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        // Parse the instruction data first - the creator it carries is needed to
+        // validate the accounts (which PDAs to expect)
+        let instruction_datas = TokenWithdrawInstructionData::try_from(data)?;
+        let accounts = TokenWithdrawAccounts::try_from((accounts, &instruction_datas.creator))?;
+
+        Ok(Self { accounts, instruction_datas })
+    }
+}
+
+impl<'a> TokenWithdraw<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &3;  // Single-byte discriminator for token withdraw instruction
+
+    pub fn process(&mut self) -> ProgramResult {
+        // Create signer seeds for PDA signing - allows the vault to sign the token transfer
+        let seeds = [
+            Seed::from(b"vault"),
+            Seed::from(self.instruction_datas.creator.as_ref()),
+            Seed::from(&self.accounts.bumps),
+        ];
+        let signers = [Signer::from(&seeds)];
+
+        Transfer {
+            from: self.accounts.vault_token_account,
+            to: self.accounts.owner_token_account,
+            authority: self.accounts.vault,
+            amount: self.instruction_datas.amount,
+        }
+        .invoke_signed(&signers)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(amount: u64, creator: Pubkey) -> [u8; 40] {
+        let mut buf = [0u8; 40];
+        buf[0..8].copy_from_slice(&amount.to_le_bytes());
+        buf[8..40].copy_from_slice(&creator);
+        buf
+    }
+
+    #[test]
+    fn instruction_data_rejects_wrong_length() {
+        assert!(TokenWithdrawInstructionData::try_from(&data(1, [1; 32])[..39]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_rejects_zero_amount() {
+        assert!(TokenWithdrawInstructionData::try_from(&data(0, [1; 32])[..]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_parses_amount_and_creator() {
+        let parsed = TokenWithdrawInstructionData::try_from(&data(7, [3; 32])[..]).unwrap();
+        assert_eq!(parsed.amount, 7);
+        assert_eq!(parsed.creator, [3; 32]);
+    }
+}
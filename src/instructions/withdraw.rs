@@ -1,81 +1,191 @@
-// Withdraw instruction: handles user withdrawals from the vault
-// Validates accounts, ensures only the owner can withdraw, and performs the lamport transfer
+// Withdraw instruction: handles withdrawals from the vault. The PDA is still seeded
+// from the original depositor (the "creator"), but release is gated on the stored
+// `withdraw_authority` rather than the creator themselves, which decouples funding
+// a vault from claiming it (payer-funds / beneficiary-claims custody patterns).
 
-use pinocchio::{account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::find_program_address, ProgramResult};
+use pinocchio::{account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{find_program_address, Pubkey}, sysvars::{clock::Clock, rent::Rent, Sysvar}, ProgramResult};
 use pinocchio_system::instructions::Transfer;
+use core::mem::size_of;
 
-// Account struct for withdraw instruction - contains owner, vault, and bump seed
+use crate::state::VaultState;
+
+// Account struct for withdraw instruction - contains authority, vault, vault_state, and bump seed
 pub struct WithdrawAccounts<'a> {
-    pub owner: &'a AccountInfo,  // User withdrawing funds (must be signer)
-    pub vault: &'a AccountInfo,  // PDA vault account containing the lamports
-    pub bumps: [u8; 1],          // Bump seed for PDA signing
+    pub authority: &'a AccountInfo,   // Signer claiming the vested balance
+    pub vault: &'a AccountInfo,       // PDA vault account containing the lamports
+    pub vault_state: &'a AccountInfo, // PDA vesting state account for the creator
+    pub bumps: [u8; 1],               // Bump seed for vault PDA signing
 }
- 
+
 // Perform sanity checks on the accounts
-impl<'a> TryFrom<&'a [AccountInfo]> for WithdrawAccounts<'a> {
+impl<'a> TryFrom<(&'a [AccountInfo], &Pubkey)> for WithdrawAccounts<'a> {
     type Error = ProgramError;
- 
-    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        // Destructure accounts slice - expect owner, vault, and system program
-        let [owner, vault, _system_program] = accounts else {
+
+    fn try_from((accounts, creator): (&'a [AccountInfo], &Pubkey)) -> Result<Self, Self::Error> {
+        // Destructure accounts slice - expect authority, vault, vault_state, and system program
+        let [authority, vault, vault_state, _system_program] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
- 
+
         // Basic account validation checks
-        if !owner.is_signer() {
-            return Err(ProgramError::InvalidAccountOwner);  // Owner must sign the transaction
+        if !authority.is_signer() {
+            return Err(ProgramError::InvalidAccountOwner);  // Authority must sign the transaction
         }
- 
+
         if unsafe { vault.owner() } != &pinocchio_system::ID {
             return Err(ProgramError::InvalidAccountOwner);  // Vault must be owned by System Program
         }
- 
-        // Verify vault is the correct PDA for this owner and get bump seed
-        let (vault_key, bump) = find_program_address(&[b"vault", owner.key().as_ref()], &crate::ID);
+
+        // Verify vault is the correct PDA for the creator and get bump seed
+        let (vault_key, bump) = find_program_address(&[b"vault", creator.as_ref()], &crate::ID);
         if &vault_key != vault.key() {
             return Err(ProgramError::InvalidAccountOwner);  // Vault must match expected PDA
-        } 
- 
-        Ok(Self { owner, vault, bumps: [bump] })
+        }
+
+        // Verify vault_state is the correct PDA for the creator and is owned by this program
+        let (vault_state_key, _) = find_program_address(&[VaultState::SEED, creator.as_ref()], &crate::ID);
+        if &vault_state_key != vault_state.key() {
+            return Err(ProgramError::InvalidAccountOwner);  // Vault state must match expected PDA
+        }
+
+        if unsafe { vault_state.owner() } != &crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);  // Vault state must be owned by this program
+        }
+
+        // Only the withdraw authority recorded at deposit time may release funds
+        let state = VaultState::load(&vault_state.try_borrow_data()?)?;
+        state.check_withdraw_authority(authority.key())?;
+
+        Ok(Self { authority, vault, vault_state, bumps: [bump] })
+    }
+}
+
+// Instruction data struct - the amount to withdraw, whether this is a full close
+// (which is allowed to drain the vault below the rent-exempt minimum), and the
+// creator whose deposit the vault/vault_state PDAs are seeded from
+pub struct WithdrawInstructionData {
+    pub amount: u64,      // Amount of lamports to withdraw
+    pub full_close: bool, // Skip the rent-exempt floor check (vault is being emptied)
+    pub creator: Pubkey,  // Original depositor, used to re-derive the vault PDAs
+}
+
+impl<'a> TryFrom<&'a [u8]> for WithdrawInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        // Verify data length matches amount (u64) + full_close flag (u8) + creator (Pubkey)
+        if data.len() != size_of::<u64>() + 1 + size_of::<Pubkey>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // Convert byte slices to their respective types (little-endian)
+        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+        let full_close = data[8] != 0;
+        let creator: Pubkey = data[9..41].try_into().unwrap();
+
+        // Instruction validation
+        if amount.eq(&0) {
+            return Err(ProgramError::InvalidInstructionData);  // Amount must be greater than 0
+        }
+
+        Ok(Self { amount, full_close, creator })
     }
 }
 
-// Main withdraw instruction struct - contains validated accounts
+// Main withdraw instruction struct - combines accounts and instruction data
 pub struct Withdraw<'a> {
     pub accounts: WithdrawAccounts<'a>,
+    pub instruction_datas: WithdrawInstructionData,
 }
- 
-impl<'a> TryFrom<&'a [AccountInfo]> for Withdraw<'a> {
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Withdraw<'a> {
     type Error = ProgramError;
- 
-    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        // Validate accounts
-        let accounts = WithdrawAccounts::try_from(accounts)?;
- 
-        Ok(Self { accounts })
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        // Parse the instruction data first - the creator it carries is needed to
+        // validate the accounts (which PDAs to expect)
+        let instruction_datas = WithdrawInstructionData::try_from(data)?;
+        let accounts = WithdrawAccounts::try_from((accounts, &instruction_datas.creator))?;
+
+        Ok(Self { accounts, instruction_datas })
     }
 }
- 
+
 impl<'a> Withdraw<'a> {
     pub const DISCRIMINATOR: &'a u8 = &1;  // Single-byte discriminator for withdraw instruction
- 
+
     pub fn process(&mut self) -> ProgramResult {
+        // Work out how much of the vesting schedule is currently available
+        let mut state_data = self.accounts.vault_state.try_borrow_mut_data()?;
+        let state = VaultState::load_mut(&mut state_data)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let available = state.available_to_withdraw(now);
+        let amount = self.instruction_datas.amount;
+
+        if amount.gt(&available) {
+            return Err(ProgramError::InvalidInstructionData);  // Can't withdraw more than has vested
+        }
+
+        // Leaving the vault below rent-exemption empties it unless this is a full close
+        if !self.instruction_datas.full_close {
+            let rent = Rent::get()?;
+            let remaining = self.accounts.vault.lamports().checked_sub(amount)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            if remaining < rent.minimum_balance(0) {
+                return Err(ProgramError::InsufficientFunds);
+            }
+        }
+
         // Create signer seeds for PDA signing - allows vault to sign the transfer
         let seeds = [
-            Seed::from(b"vault"),                                    // Seed: "vault"
-            Seed::from(self.accounts.owner.key().as_ref()),          // Seed: owner's public key
-            Seed::from(&self.accounts.bumps),                        // Seed: bump
+            Seed::from(b"vault"),                                          // Seed: "vault"
+            Seed::from(self.instruction_datas.creator.as_ref()),           // Seed: creator's public key
+            Seed::from(&self.accounts.bumps),                              // Seed: bump
         ];
         let signers = [Signer::from(&seeds)];  // Create signer from seeds
- 
-        // Transfer all lamports from vault back to owner using signed CPI
+
+        // Transfer the requested amount from vault to the withdraw authority
         Transfer {
             from: self.accounts.vault,                    // Source account (vault)
-            to: self.accounts.owner,                      // Destination account (owner)
-            lamports: self.accounts.vault.lamports(),     // Transfer all available lamports
+            to: self.accounts.authority,                  // Destination account (withdraw authority)
+            lamports: amount,                             // Transfer only the requested amount
         }
         .invoke_signed(&signers)?;  // Execute transfer with PDA signature
- 
+
+        state.already_withdrawn += amount;
+
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(amount: u64, full_close: bool, creator: Pubkey) -> [u8; 41] {
+        let mut buf = [0u8; 41];
+        buf[0..8].copy_from_slice(&amount.to_le_bytes());
+        buf[8] = full_close as u8;
+        buf[9..41].copy_from_slice(&creator);
+        buf
+    }
+
+    #[test]
+    fn instruction_data_rejects_wrong_length() {
+        assert!(WithdrawInstructionData::try_from(&data(1, false, [1; 32])[..40]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_rejects_zero_amount() {
+        assert!(WithdrawInstructionData::try_from(&data(0, false, [1; 32])[..]).is_err());
+    }
+
+    #[test]
+    fn instruction_data_parses_amount_full_close_and_creator() {
+        let parsed = WithdrawInstructionData::try_from(&data(5, true, [2; 32])[..]).unwrap();
+        assert_eq!(parsed.amount, 5);
+        assert!(parsed.full_close);
+        assert_eq!(parsed.creator, [2; 32]);
+    }
+}
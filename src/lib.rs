@@ -1,4 +1,4 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![allow(unexpected_cfgs)]
 use pinocchio::{account_info::AccountInfo, entrypoint, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
 use pinocchio::{
@@ -14,6 +14,10 @@ nostd_panic_handler!();
 // Import our instruction modules
 pub mod instructions;
 pub use instructions::*;
+
+// On-chain account layouts owned by this program
+pub mod state;
+pub use state::*;
  
 
 // Program ID - unique identifier for this deployed program
@@ -27,7 +31,9 @@ pub const ID: Pubkey = [
  
  
 // Main instruction processor - routes incoming instructions to appropriate handlers
-// Uses single-byte discriminators (0 for Deposit, 1 for Withdraw) to identify instructions
+// Uses single-byte discriminators (0 for Deposit, 1 for Withdraw, 2 for TokenDeposit,
+// 3 for TokenWithdraw, 4 for Relay, 5 for CreateVault, 6 for CloseVault) to identify
+// instructions
 fn process_instruction(
     _program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -37,7 +43,17 @@ fn process_instruction(
         // Route to Deposit instruction handler (discriminator = 0)
         Some((Deposit::DISCRIMINATOR, data)) => Deposit::try_from((data, accounts))?.process(),
         // Route to Withdraw instruction handler (discriminator = 1)
-        Some((Withdraw::DISCRIMINATOR, _)) => Withdraw::try_from(accounts)?.process(),
+        Some((Withdraw::DISCRIMINATOR, data)) => Withdraw::try_from((data, accounts))?.process(),
+        // Route to TokenDeposit instruction handler (discriminator = 2)
+        Some((TokenDeposit::DISCRIMINATOR, data)) => TokenDeposit::try_from((data, accounts))?.process(),
+        // Route to TokenWithdraw instruction handler (discriminator = 3)
+        Some((TokenWithdraw::DISCRIMINATOR, data)) => TokenWithdraw::try_from((data, accounts))?.process(),
+        // Route to Relay instruction handler (discriminator = 4)
+        Some((Relay::DISCRIMINATOR, data)) => Relay::try_from((data, accounts))?.process(),
+        // Route to CreateVault instruction handler (discriminator = 5)
+        Some((CreateVault::DISCRIMINATOR, _)) => CreateVault::try_from(accounts)?.process(),
+        // Route to CloseVault instruction handler (discriminator = 6)
+        Some((CloseVault::DISCRIMINATOR, data)) => CloseVault::try_from((data, accounts))?.process(),
         // Invalid instruction if discriminator doesn't match known instructions
         _ => Err(ProgramError::InvalidInstructionData)
     }
@@ -0,0 +1,5 @@
+// State module: on-chain account layouts owned by this program.
+
+pub mod vault_state;
+
+pub use vault_state::*;
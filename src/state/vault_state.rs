@@ -0,0 +1,129 @@
+// Vault state account: tracks the vesting schedule and withdraw authority for a
+// lamport vault. Seeded `[b"vault_state", owner]`, owned by `crate::ID`, created at
+// deposit time, where `owner` is the original depositor the vault PDA is seeded to.
+
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+#[repr(C)]
+pub struct VaultState {
+    pub start_ts: i64,               // Vesting start timestamp (unix seconds)
+    pub end_ts: i64,                 // Vesting end timestamp (unix seconds)
+    pub total_deposited: u64,        // Total lamports ever deposited for this owner
+    pub already_withdrawn: u64,      // Lamports already released to the withdraw authority
+    pub withdraw_authority: Pubkey,  // Only signer allowed to withdraw, set at creation time
+}
+
+impl VaultState {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+    pub const SEED: &'static [u8] = b"vault_state";
+
+    // Linear vesting: fully unlocked at `end_ts`, locked before `start_ts`.
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now >= self.end_ts {
+            self.total_deposited
+        } else if now <= self.start_ts {
+            0
+        } else {
+            let elapsed = (now - self.start_ts) as u128;
+            let duration = (self.end_ts - self.start_ts) as u128;
+            (self.total_deposited as u128 * elapsed / duration) as u64
+        }
+    }
+
+    // Lamports vested but not yet claimed, as of `now`.
+    pub fn available_to_withdraw(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.already_withdrawn)
+    }
+
+    // Whether the full deposit has been claimed, i.e. it's safe to close the vault.
+    pub fn is_fully_paid_out(&self) -> bool {
+        self.already_withdrawn.eq(&self.total_deposited)
+    }
+
+    // Only the recorded withdraw authority may withdraw, relay, or close the vault.
+    pub fn check_withdraw_authority(&self, signer: &Pubkey) -> Result<(), ProgramError> {
+        if signer.ne(&self.withdraw_authority) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        Ok(())
+    }
+
+    pub fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(unsafe { &*(data.as_ptr() as *const Self) })
+    }
+
+    pub fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(unsafe { &mut *(data.as_mut_ptr() as *mut Self) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(withdraw_authority: Pubkey) -> VaultState {
+        VaultState {
+            start_ts: 100,
+            end_ts: 200,
+            total_deposited: 1_000,
+            already_withdrawn: 0,
+            withdraw_authority,
+        }
+    }
+
+    #[test]
+    fn vested_amount_before_start_is_zero() {
+        let s = state([0; 32]);
+        assert_eq!(s.vested_amount(0), 0);
+        assert_eq!(s.vested_amount(100), 0);  // at start_ts, still 0
+    }
+
+    #[test]
+    fn vested_amount_at_end_is_total() {
+        let s = state([0; 32]);
+        assert_eq!(s.vested_amount(200), 1_000);
+        assert_eq!(s.vested_amount(1_000), 1_000);  // anything past end_ts is capped at total
+    }
+
+    #[test]
+    fn vested_amount_mid_schedule_is_linear() {
+        let s = state([0; 32]);
+        assert_eq!(s.vested_amount(150), 500);  // halfway through the window
+        assert_eq!(s.vested_amount(175), 750);
+    }
+
+    #[test]
+    fn available_to_withdraw_subtracts_already_claimed() {
+        let mut s = state([0; 32]);
+        s.already_withdrawn = 300;
+        assert_eq!(s.available_to_withdraw(150), 200);  // 500 vested - 300 claimed
+    }
+
+    #[test]
+    fn is_fully_paid_out_requires_full_deposit_claimed() {
+        let mut s = state([0; 32]);
+        assert!(!s.is_fully_paid_out());
+        s.already_withdrawn = s.total_deposited;
+        assert!(s.is_fully_paid_out());
+    }
+
+    #[test]
+    fn check_withdraw_authority_accepts_matching_signer() {
+        let authority = [7; 32];
+        let s = state(authority);
+        assert!(s.check_withdraw_authority(&authority).is_ok());
+    }
+
+    #[test]
+    fn check_withdraw_authority_rejects_mismatched_signer() {
+        let s = state([7; 32]);
+        let err = s.check_withdraw_authority(&[9; 32]).unwrap_err();
+        assert_eq!(err, ProgramError::MissingRequiredSignature);
+    }
+}